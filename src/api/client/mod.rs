@@ -0,0 +1,270 @@
+//! Anytype API client
+//!
+//! Thin HTTP wrapper handling authentication plus a shared retry/backoff and
+//! rate-limiting policy for every `objects` call.
+
+pub mod objects;
+
+pub use objects::*;
+
+use crate::error::{Error, Result};
+use rand::Rng;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+const DEFAULT_BASE_URL: &str = "http://localhost:31009";
+
+/// Retry/backoff and request-rate configuration for an `AnytypeClient`
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Maximum number of attempts for a request before giving up
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub backoff_base: Duration,
+    /// Multiplier applied to the delay after each subsequent retry
+    pub backoff_factor: f64,
+    /// Upper bound on any single backoff delay
+    pub backoff_max: Duration,
+    /// Maximum number of requests per second sent to the API
+    pub rps: u32,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            backoff_base: Duration::from_millis(500),
+            backoff_factor: 2.0,
+            backoff_max: Duration::from_secs(30),
+            rps: 10,
+        }
+    }
+}
+
+/// Client used to talk to the Anytype API
+#[derive(Debug, Clone)]
+pub struct AnytypeClient {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+    config: ClientConfig,
+    limiter: Arc<Mutex<TokenBucket>>,
+}
+
+/// A simple token-bucket rate limiter shared by every request a client sends
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(rps: u32) -> Self {
+        let rps = rps.max(1) as f64;
+        Self {
+            capacity: rps,
+            tokens: rps,
+            refill_per_sec: rps,
+            last_refill: Instant::now(),
+        }
+    }
+
+    async fn acquire(&mut self) {
+        loop {
+            let elapsed = self.last_refill.elapsed().as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+            self.last_refill = Instant::now();
+
+            if self.tokens >= 1.0 {
+                self.tokens -= 1.0;
+                return;
+            }
+
+            let wait = Duration::from_secs_f64((1.0 - self.tokens) / self.refill_per_sec);
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+impl AnytypeClient {
+    /// Create a new client with the default retry/rate-limit configuration
+    pub fn new() -> Result<Self> {
+        Self::with_config(ClientConfig::default())
+    }
+
+    /// Create a new client using a custom retry/rate-limit configuration
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        Ok(Self {
+            http: reqwest::Client::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            limiter: Arc::new(Mutex::new(TokenBucket::new(config.rps))),
+            config,
+            api_key: None,
+        })
+    }
+
+    /// Set the API key used to authenticate requests
+    pub fn set_api_key(&mut self, api_key: String) {
+        self.api_key = Some(api_key);
+    }
+
+    fn request_builder(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}{}", self.base_url, path);
+        let mut builder = self.http.request(method, url);
+        if let Some(api_key) = &self.api_key {
+            builder = builder.bearer_auth(api_key);
+        }
+        builder
+    }
+
+    /// Send a request, transparently retrying on transport-level failures
+    /// (timeouts, connection resets, DNS blips) as well as `429`/`5xx`
+    /// responses, with exponential backoff and jitter, honoring
+    /// `Retry-After` when present, and gating every attempt through the
+    /// token-bucket rate limiter.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<T> {
+        let mut attempt = 0;
+
+        loop {
+            self.limiter.lock().await.acquire().await;
+
+            let mut builder = self.request_builder(method.clone(), path);
+            if let Some(body) = body {
+                builder = builder.json(body);
+            }
+
+            let response = match builder.send().await {
+                Ok(response) => response,
+                Err(err) => {
+                    attempt += 1;
+                    if attempt >= self.config.max_retries {
+                        return Err(err.into());
+                    }
+
+                    let delay = backoff_delay(&self.config, attempt);
+                    warn!(
+                        "{} {} failed: {}, retrying in {:?} (attempt {}/{})",
+                        method, path, err, delay, attempt, self.config.max_retries
+                    );
+
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+            };
+
+            let status = response.status();
+
+            if status.as_u16() == 429 || status.is_server_error() {
+                attempt += 1;
+                if attempt >= self.config.max_retries {
+                    return Err(Error::Api(format!(
+                        "request to {path} failed after {attempt} attempts with status {status}"
+                    )));
+                }
+
+                let delay = retry_after(&response).unwrap_or_else(|| backoff_delay(&self.config, attempt));
+
+                warn!(
+                    "{} {} returned {}, retrying in {:?} (attempt {}/{})",
+                    method, path, status, delay, attempt, self.config.max_retries
+                );
+
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            debug!("{} {} -> {}", method, path, status);
+            return Ok(response.json().await?);
+        }
+    }
+
+    pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(reqwest::Method::GET, path, None).await
+    }
+
+    pub(crate) async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let value = serde_json::to_value(body)?;
+        self.send(reqwest::Method::POST, path, Some(&value)).await
+    }
+
+    pub(crate) async fn patch<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T> {
+        let value = serde_json::to_value(body)?;
+        self.send(reqwest::Method::PATCH, path, Some(&value)).await
+    }
+
+    pub(crate) async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(reqwest::Method::DELETE, path, None).await
+    }
+}
+
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn backoff_delay(config: &ClientConfig, attempt: u32) -> Duration {
+    let exp = config.backoff_base.as_secs_f64() * config.backoff_factor.powi(attempt as i32 - 1);
+    let capped = exp.min(config.backoff_max.as_secs_f64());
+    let jitter = rand::thread_rng().gen_range(0.0..capped.max(0.001));
+    Duration::from_secs_f64(jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_stays_within_exponential_ceiling_and_cap() {
+        let config = ClientConfig {
+            max_retries: 5,
+            backoff_base: Duration::from_millis(500),
+            backoff_factor: 2.0,
+            backoff_max: Duration::from_secs(30),
+            rps: 10,
+        };
+
+        // Each attempt's jittered delay should never exceed the exponential
+        // ceiling for that attempt, nor the overall backoff_max.
+        for attempt in 1..=8 {
+            let ceiling = (config.backoff_base.as_secs_f64()
+                * config.backoff_factor.powi(attempt as i32 - 1))
+            .min(config.backoff_max.as_secs_f64());
+
+            let delay = backoff_delay(&config, attempt).as_secs_f64();
+            assert!(delay <= ceiling + f64::EPSILON);
+            assert!(delay <= config.backoff_max.as_secs_f64() + f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn token_bucket_starts_full_at_rps_capacity() {
+        let bucket = TokenBucket::new(5);
+        assert_eq!(bucket.capacity, 5.0);
+        assert_eq!(bucket.tokens, 5.0);
+    }
+}
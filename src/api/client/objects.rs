@@ -2,9 +2,20 @@
 //!
 //! Handles object management operations.
 
+mod batch;
+mod filter;
+
+pub use batch::{parse_ndjson, BatchOperation, BatchReport};
+
 use super::AnytypeClient;
-use crate::{error::Result, types::Pagination};
+use crate::{
+    error::{Error, Result},
+    types::Pagination,
+};
+use filter::parse_filter;
+use futures::stream::{self, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use tracing::{debug, info};
 
 /// Object information
@@ -15,6 +26,8 @@ pub struct Object {
     pub space_id: Option<String>,
     pub object: Option<String>, // object type
     pub properties: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
     // Add more fields as needed
 }
 
@@ -33,6 +46,8 @@ pub struct CreateObjectRequest {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
 }
 
 /// Response when creating an object
@@ -68,6 +83,19 @@ pub struct UpdateObjectResponse {
     pub markdown: Option<String>,
 }
 
+/// Request body for searching objects in a space
+#[derive(Debug, Serialize)]
+struct SearchObjectsRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<usize>,
+}
+
 impl AnytypeClient {
     /// List objects in a space
     pub async fn list_objects(&self, space_id: &str) -> Result<Vec<Object>> {
@@ -199,4 +227,352 @@ impl AnytypeClient {
 
         Ok(all_objects)
     }
+
+    /// List objects in a space as a lazily-paginated stream: pages are
+    /// fetched only as the consumer pulls, and the next page is prefetched
+    /// in the background as the last object of the current one is handed
+    /// out. Stops as soon as `limit` items have been yielded, so `--limit 5`
+    /// against a large space issues a single request. Once ended, the stream
+    /// keeps returning `None` if polled again.
+    pub fn list_objects_stream(
+        &self,
+        space_id: &str,
+        limit: Option<usize>,
+    ) -> impl Stream<Item = Result<Object>> {
+        struct State {
+            client: AnytypeClient,
+            space_id: String,
+            offset: usize,
+            total: usize,
+            has_more: bool,
+            buffer: VecDeque<Object>,
+            prefetch: Option<tokio::task::JoinHandle<Result<ListObjectsResponse>>>,
+            yielded: usize,
+            limit: Option<usize>,
+            /// Set once a page fetch fails, so the stream ends instead of
+            /// repeating the same failing request on the next poll.
+            failed: bool,
+        }
+
+        let state = State {
+            client: self.clone(),
+            space_id: space_id.to_string(),
+            offset: 0,
+            total: usize::MAX,
+            has_more: true,
+            buffer: VecDeque::new(),
+            prefetch: None,
+            yielded: 0,
+            limit,
+            failed: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if state.failed {
+                    return None;
+                }
+
+                if let Some(limit) = state.limit {
+                    if state.yielded >= limit {
+                        return None;
+                    }
+                }
+
+                if let Some(object) = state.buffer.pop_front() {
+                    state.yielded += 1;
+
+                    // Once this page's last object is handed out, start fetching
+                    // the next page in the background so it's ready by the time
+                    // we need it, unless `limit` has just been reached.
+                    if state.buffer.is_empty()
+                        && state.prefetch.is_none()
+                        && state.has_more
+                        && state.offset < state.total
+                        && state.limit.map_or(true, |limit| state.yielded < limit)
+                    {
+                        let client = state.client.clone();
+                        let space_id = state.space_id.clone();
+                        let offset = state.offset;
+                        state.prefetch = Some(tokio::spawn(async move {
+                            client
+                                .list_objects_with_pagination(&space_id, None, Some(offset))
+                                .await
+                        }));
+                    }
+
+                    return Some((Ok(object), state));
+                }
+
+                if !state.has_more || state.offset >= state.total {
+                    return None;
+                }
+
+                let response = match state.prefetch.take() {
+                    Some(handle) => match handle.await {
+                        Ok(result) => result,
+                        Err(join_err) => Err(Error::Api(join_err.to_string())),
+                    },
+                    None => {
+                        state
+                            .client
+                            .list_objects_with_pagination(&state.space_id, None, Some(state.offset))
+                            .await
+                    }
+                };
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(err) => {
+                        state.failed = true;
+                        return Some((Err(err), state));
+                    }
+                };
+
+                state.has_more = response.pagination.has_more;
+                state.total = response.pagination.total;
+                state.offset += response.data.len();
+                state.buffer.extend(response.data);
+
+                if state.buffer.is_empty() {
+                    return None;
+                }
+            }
+        })
+        .fuse()
+    }
+
+    /// Search objects in a space with a free-text query plus an optional
+    /// client-side filter expression (see the `filter` module for the DSL
+    /// grammar). Pages through the search endpoint, filtering each page as
+    /// it arrives, until `limit` matches have been found or pages run out —
+    /// so a filter never misses matches that live past the first page.
+    pub async fn search_objects(
+        &self,
+        space_id: &str,
+        query: Option<&str>,
+        filter: Option<&str>,
+        sort: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<Vec<Object>> {
+        info!("Searching objects in space: {}", space_id);
+
+        const PAGE_SIZE: usize = 100;
+
+        let filter_expr = filter.map(parse_filter).transpose()?;
+
+        let mut offset = 0;
+        let mut has_more = true;
+        let mut total = usize::MAX;
+        let mut results = Vec::new();
+
+        while has_more {
+            let request = SearchObjectsRequest {
+                query,
+                sort,
+                limit: Some(PAGE_SIZE),
+                offset: Some(offset),
+            };
+            debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+
+            let response: ListObjectsResponse = self
+                .post(&format!("/v1/spaces/{space_id}/search"), &request)
+                .await?;
+
+            has_more = response.pagination.has_more;
+            total = response.pagination.total;
+
+            let page_len = response.data.len();
+            offset += page_len;
+
+            results.extend(
+                response
+                    .data
+                    .into_iter()
+                    .filter(|obj| filter_expr.as_ref().map_or(true, |expr| expr.evaluate(obj))),
+            );
+
+            let limit_reached = limit.is_some_and(|limit| results.len() >= limit);
+            if limit_reached || !has_more || offset >= total || page_len == 0 {
+                break;
+            }
+        }
+
+        if let Some(limit) = limit {
+            results.truncate(limit);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Build a client talking to `base_url`, with retries disabled so a
+    /// connection failure surfaces immediately instead of after a backoff
+    /// loop.
+    fn test_client(base_url: &str) -> AnytypeClient {
+        AnytypeClient {
+            http: reqwest::Client::new(),
+            base_url: base_url.to_string(),
+            api_key: None,
+            config: super::super::ClientConfig {
+                max_retries: 1,
+                ..super::super::ClientConfig::default()
+            },
+            limiter: std::sync::Arc::new(tokio::sync::Mutex::new(super::super::TokenBucket::new(
+                1000,
+            ))),
+        }
+    }
+
+    /// Serve one canned JSON response per accepted connection, in order.
+    async fn mock_pages_server(pages: Vec<String>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            for body in pages {
+                if let Ok((mut socket, _)) = listener.accept().await {
+                    let mut buf = [0u8; 1024];
+                    let _ = socket.read(&mut buf).await;
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                }
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    fn page(objects: serde_json::Value, total: usize, offset: usize, has_more: bool) -> String {
+        serde_json::json!({
+            "data": objects,
+            "pagination": {
+                "total": total,
+                "offset": offset,
+                "limit": 2,
+                "has_more": has_more,
+            },
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn list_objects_stream_pages_through_offset_and_stops_when_exhausted() {
+        let page1 = page(
+            serde_json::json!([
+                {"id": "1", "name": "A", "space_id": null, "object": null, "properties": {}},
+                {"id": "2", "name": "B", "space_id": null, "object": null, "properties": {}},
+            ]),
+            3,
+            0,
+            true,
+        );
+        let page2 = page(
+            serde_json::json!([
+                {"id": "3", "name": "C", "space_id": null, "object": null, "properties": {}},
+            ]),
+            3,
+            2,
+            false,
+        );
+
+        let base_url = mock_pages_server(vec![page1, page2]).await;
+        let client = test_client(&base_url);
+
+        let mut stream = Box::pin(client.list_objects_stream("space1", None));
+        let mut ids = Vec::new();
+        while let Some(object) = stream.next().await {
+            ids.push(object.unwrap().id);
+        }
+
+        assert_eq!(ids, vec!["1", "2", "3"]);
+        // The stream must end on its own rather than the consumer giving up.
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn list_objects_stream_stops_before_fetching_past_limit() {
+        let page1 = page(
+            serde_json::json!([
+                {"id": "1", "name": "A", "space_id": null, "object": null, "properties": {}},
+                {"id": "2", "name": "B", "space_id": null, "object": null, "properties": {}},
+            ]),
+            3,
+            0,
+            true,
+        );
+        // Only one connection is ever accepted; a second page request would
+        // hang forever, catching a stream that over-fetches past `limit`.
+        let base_url = mock_pages_server(vec![page1]).await;
+        let client = test_client(&base_url);
+
+        let mut stream = Box::pin(client.list_objects_stream("space1", Some(1)));
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.id, "1");
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn list_objects_stream_terminates_after_a_failed_page_fetch() {
+        // Nothing is listening here, so the very first request fails fast
+        // with a connection error rather than hanging.
+        let client = test_client("http://127.0.0.1:1");
+
+        let mut stream = Box::pin(client.list_objects_stream("space1", None));
+        assert!(stream.next().await.unwrap().is_err());
+        // A second poll must end the stream, not repeat the failing request.
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn search_objects_paginates_and_filters_across_pages() {
+        let page1 = page(
+            serde_json::json!([
+                {"id": "1", "name": "A", "space_id": null, "object": null, "properties": {"status": "done"}},
+                {"id": "2", "name": "B", "space_id": null, "object": null, "properties": {"status": "todo"}},
+            ]),
+            3,
+            0,
+            true,
+        );
+        let page2 = page(
+            serde_json::json!([
+                {"id": "3", "name": "C", "space_id": null, "object": null, "properties": {"status": "done"}},
+            ]),
+            3,
+            2,
+            false,
+        );
+
+        let base_url = mock_pages_server(vec![page1, page2]).await;
+        let client = test_client(&base_url);
+
+        let results = client
+            .search_objects("space1", None, Some("status = \"done\""), None, None)
+            .await
+            .unwrap();
+
+        let ids: Vec<&str> = results.iter().map(|obj| obj.id.as_str()).collect();
+        assert_eq!(ids, vec!["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn search_objects_propagates_a_page_fetch_error_instead_of_looping() {
+        let client = test_client("http://127.0.0.1:1");
+
+        let result = client.search_objects("space1", None, None, None, None).await;
+        assert!(result.is_err());
+    }
 }
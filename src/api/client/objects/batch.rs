@@ -0,0 +1,277 @@
+//! Batch object operations
+//!
+//! Applies a newline-delimited JSON file of create/update/delete operations
+//! against a space with bounded concurrency, used by
+//! `AnytypeClient::batch_objects`.
+
+use super::{AnytypeClient, CreateObjectRequest, UpdateObjectRequest};
+use crate::error::Result;
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// A single operation parsed from a batch NDJSON file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum BatchOperation {
+    Create {
+        type_key: String,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        properties: Option<serde_json::Value>,
+    },
+    Update {
+        object_id: String,
+        #[serde(default)]
+        name: Option<String>,
+        #[serde(default)]
+        properties: Option<serde_json::Value>,
+    },
+    Delete { object_id: String },
+}
+
+impl BatchOperation {
+    /// The object this operation targets, if any. `Create` has none yet, so
+    /// it never contends with another operation for ordering purposes.
+    fn object_id(&self) -> Option<&str> {
+        match self {
+            BatchOperation::Create { .. } => None,
+            BatchOperation::Update { object_id, .. } => Some(object_id),
+            BatchOperation::Delete { object_id } => Some(object_id),
+        }
+    }
+}
+
+/// Summary of a batch run: per-line outcomes rolled up into totals
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// (line_number, error) for each line that failed
+    pub errors: Vec<(usize, String)>,
+}
+
+/// Parse a newline-delimited JSON batch file into operations, keeping the
+/// 1-based line number alongside each one for error reporting
+pub fn parse_ndjson(content: &str) -> Result<Vec<(usize, BatchOperation)>> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let op = serde_json::from_str(line)?;
+            Ok((i + 1, op))
+        })
+        .collect()
+}
+
+/// Partition operations into groups that can safely run concurrently: every
+/// operation sharing an `object_id` lands in the same group, in its original
+/// order, while each id-less `Create` gets its own singleton group.
+fn group_by_object_id(
+    operations: Vec<(usize, BatchOperation)>,
+) -> Vec<Vec<(usize, BatchOperation)>> {
+    let mut groups: Vec<Vec<(usize, BatchOperation)>> = Vec::new();
+    let mut group_by_id: HashMap<String, usize> = HashMap::new();
+
+    for (line_number, op) in operations {
+        match op.object_id() {
+            Some(object_id) => {
+                let index = *group_by_id
+                    .entry(object_id.to_string())
+                    .or_insert_with(|| {
+                        groups.push(Vec::new());
+                        groups.len() - 1
+                    });
+                groups[index].push((line_number, op));
+            }
+            None => groups.push(vec![(line_number, op)]),
+        }
+    }
+
+    groups
+}
+
+impl AnytypeClient {
+    async fn apply_batch_operation(&self, space_id: &str, op: BatchOperation) -> Result<()> {
+        match op {
+            BatchOperation::Create {
+                type_key,
+                name,
+                properties,
+            } => {
+                self.create_object(
+                    space_id,
+                    CreateObjectRequest {
+                        type_key,
+                        name,
+                        properties,
+                        markdown: None,
+                    },
+                )
+                .await?;
+            }
+            BatchOperation::Update {
+                object_id,
+                name,
+                properties,
+            } => {
+                self.update_object(
+                    space_id,
+                    &object_id,
+                    UpdateObjectRequest {
+                        name,
+                        properties,
+                        markdown: None,
+                    },
+                )
+                .await?;
+            }
+            BatchOperation::Delete { object_id } => {
+                self.delete_object(space_id, &object_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply a batch of create/update/delete operations to a space. Operations
+    /// that target the same `object_id` run strictly in file order, since
+    /// racing e.g. two updates (or an update then a delete) against one
+    /// object could otherwise leave the space in a different state than a
+    /// sequential run would produce. Operations on different ids — including
+    /// every `Create`, which has no id yet to contend on — run concurrently,
+    /// up to `parallelism` groups at a time. Callers that want a dry run
+    /// should validate/print the parsed operations themselves and skip
+    /// calling this at all, since every operation here is applied.
+    pub async fn batch_objects(
+        &self,
+        space_id: &str,
+        operations: Vec<(usize, BatchOperation)>,
+        parallelism: usize,
+    ) -> BatchReport {
+        let groups = group_by_object_id(operations);
+
+        let outcomes = stream::iter(groups)
+            .map(|group| async move {
+                let mut results = Vec::with_capacity(group.len());
+                for (line_number, op) in group {
+                    let result = self.apply_batch_operation(space_id, op).await;
+                    results.push((line_number, result.map_err(|err| err.to_string())));
+                }
+                results
+            })
+            .buffer_unordered(parallelism.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .flatten();
+
+        let mut report = BatchReport::default();
+        for (line_number, outcome) in outcomes {
+            match outcome {
+                Ok(()) => report.succeeded += 1,
+                Err(message) => {
+                    report.failed += 1;
+                    report.errors.push((line_number, message));
+                }
+            }
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_operations_and_keeps_line_numbers() {
+        let content = "\n{\"op\":\"create\",\"type_key\":\"page\",\"name\":\"A\"}\n{\"op\":\"update\",\"object_id\":\"obj1\"}\n{\"op\":\"delete\",\"object_id\":\"obj2\"}\n";
+        let ops = parse_ndjson(content).unwrap();
+
+        assert_eq!(ops.len(), 3);
+        assert_eq!(ops[0].0, 2);
+        assert!(matches!(ops[0].1, BatchOperation::Create { .. }));
+        assert_eq!(ops[1].0, 3);
+        assert!(matches!(ops[1].1, BatchOperation::Update { .. }));
+        assert_eq!(ops[2].0, 4);
+        assert!(matches!(ops[2].1, BatchOperation::Delete { .. }));
+    }
+
+    #[test]
+    fn rejects_malformed_line() {
+        let content = "{\"op\":\"create\",\"type_key\":\"page\"}\nnot json\n";
+        assert!(parse_ndjson(content).is_err());
+    }
+
+    #[test]
+    fn groups_operations_sharing_an_object_id_in_file_order() {
+        let ops = vec![
+            (
+                1,
+                BatchOperation::Update {
+                    object_id: "obj1".to_string(),
+                    name: Some("first update".to_string()),
+                    properties: None,
+                },
+            ),
+            (
+                2,
+                BatchOperation::Create {
+                    type_key: "page".to_string(),
+                    name: None,
+                    properties: None,
+                },
+            ),
+            (
+                3,
+                BatchOperation::Delete {
+                    object_id: "obj1".to_string(),
+                },
+            ),
+        ];
+
+        let groups = group_by_object_id(ops);
+
+        // obj1's update and delete must land in the same group, in order...
+        let obj1_group = groups
+            .iter()
+            .find(|group| group.len() > 1)
+            .expect("obj1's operations should be grouped together");
+        assert_eq!(
+            obj1_group.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+
+        // ...while the id-less create gets its own group, free to run
+        // concurrently with obj1's group.
+        assert!(groups.iter().any(|group| group.len() == 1
+            && matches!(group[0].1, BatchOperation::Create { .. })));
+        assert_eq!(groups.len(), 2);
+    }
+
+    #[test]
+    fn independent_object_ids_get_separate_groups() {
+        let ops = vec![
+            (
+                1,
+                BatchOperation::Delete {
+                    object_id: "obj1".to_string(),
+                },
+            ),
+            (
+                2,
+                BatchOperation::Delete {
+                    object_id: "obj2".to_string(),
+                },
+            ),
+        ];
+
+        let groups = group_by_object_id(ops);
+        assert_eq!(groups.len(), 2);
+        assert!(groups.iter().all(|group| group.len() == 1));
+    }
+}
@@ -0,0 +1,486 @@
+//! Filter expression DSL
+//!
+//! A small recursive-descent parser for the filter expressions accepted by
+//! `AnytypeClient::search_objects`, e.g. `status = "done" AND priority > 3`.
+//! `AND` binds tighter than `OR`; `NOT` and parentheses work as expected.
+
+use super::Object;
+use serde_json::Value;
+use std::fmt;
+
+/// A parsed filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+    Not(Box<FilterExpr>),
+    Compare {
+        field: String,
+        op: CompareOp,
+        value: Literal,
+    },
+}
+
+/// Comparison operators supported by the filter DSL
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Contains,
+}
+
+/// A literal value in a filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    String(String),
+    Number(f64),
+    Bool(bool),
+}
+
+/// Error produced when a filter expression fails to parse
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterParseError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for FilterParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid filter at position {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for FilterParseError {}
+
+impl From<FilterParseError> for crate::error::Error {
+    fn from(err: FilterParseError) -> Self {
+        crate::error::Error::Api(err.to_string())
+    }
+}
+
+impl FilterExpr {
+    /// Evaluate this expression against an object's properties, treating a
+    /// missing field as a non-match
+    pub fn evaluate(&self, obj: &Object) -> bool {
+        match self {
+            FilterExpr::And(lhs, rhs) => lhs.evaluate(obj) && rhs.evaluate(obj),
+            FilterExpr::Or(lhs, rhs) => lhs.evaluate(obj) || rhs.evaluate(obj),
+            FilterExpr::Not(inner) => !inner.evaluate(obj),
+            FilterExpr::Compare { field, op, value } => {
+                match lookup_path(&obj.properties, field) {
+                    Some(actual) => compare(actual, *op, value),
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+/// Look up a (possibly dotted) path in a JSON value, e.g. `details.status`
+fn lookup_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, segment| current.get(segment))
+}
+
+fn compare(actual: &Value, op: CompareOp, expected: &Literal) -> bool {
+    match op {
+        CompareOp::Eq => literal_eq(actual, expected),
+        CompareOp::Ne => !literal_eq(actual, expected),
+        CompareOp::Contains => match (actual, expected) {
+            (Value::String(s), Literal::String(needle)) => s.contains(needle.as_str()),
+            (Value::Array(items), _) => items.iter().any(|item| literal_eq(item, expected)),
+            _ => false,
+        },
+        CompareOp::Gt | CompareOp::Ge | CompareOp::Lt | CompareOp::Le => {
+            match (coerce_number(actual), expected) {
+                (Some(actual), Literal::Number(expected)) => match op {
+                    CompareOp::Gt => actual > *expected,
+                    CompareOp::Ge => actual >= *expected,
+                    CompareOp::Lt => actual < *expected,
+                    CompareOp::Le => actual <= *expected,
+                    _ => unreachable!(),
+                },
+                _ => false,
+            }
+        }
+    }
+}
+
+fn literal_eq(actual: &Value, expected: &Literal) -> bool {
+    match expected {
+        Literal::String(s) => coerce_string(actual).as_deref() == Some(s.as_str()),
+        Literal::Number(n) => coerce_number(actual) == Some(*n),
+        Literal::Bool(b) => coerce_bool(actual) == Some(*b),
+    }
+}
+
+/// Coerce a JSON value to a number, parsing numeric strings as well as
+/// reading JSON numbers directly — Anytype's flattened properties can come
+/// back as either depending on the property type.
+fn coerce_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        _ => value.as_f64(),
+    }
+}
+
+/// Coerce a JSON value to a bool, parsing `"true"`/`"false"` strings as well
+/// as reading JSON booleans directly.
+fn coerce_bool(value: &Value) -> Option<bool> {
+    match value {
+        Value::String(s) => s.parse().ok(),
+        _ => value.as_bool(),
+    }
+}
+
+/// Coerce a JSON value to its string form for string-literal comparisons.
+fn coerce_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Parse a filter expression string into a `FilterExpr`
+pub fn parse_filter(input: &str) -> Result<FilterExpr, FilterParseError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_or()?;
+    parser.expect_end()?;
+    Ok(expr)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    String(String),
+    Number(f64),
+    Bool(bool),
+    Op(CompareOp),
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, FilterParseError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push((Token::LParen, start));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((Token::RParen, start));
+                i += 1;
+            }
+            '=' => {
+                tokens.push((Token::Op(CompareOp::Eq), start));
+                i += 1;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push((Token::Op(CompareOp::Ne), start));
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push((Token::Op(CompareOp::Ge), start));
+                i += 2;
+            }
+            '>' => {
+                tokens.push((Token::Op(CompareOp::Gt), start));
+                i += 1;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push((Token::Op(CompareOp::Le), start));
+                i += 2;
+            }
+            '<' => {
+                tokens.push((Token::Op(CompareOp::Lt), start));
+                i += 1;
+            }
+            '"' => {
+                i += 1;
+                let mut s = String::new();
+                while i < chars.len() && chars[i] != '"' {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(FilterParseError {
+                        message: "unterminated string literal".to_string(),
+                        position: start,
+                    });
+                }
+                i += 1; // closing quote
+                tokens.push((Token::String(s), start));
+            }
+            c if c.is_ascii_digit() => {
+                let mut s = String::new();
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                let n: f64 = s.parse().map_err(|_| FilterParseError {
+                    message: format!("invalid number literal '{s}'"),
+                    position: start,
+                })?;
+                tokens.push((Token::Number(n), start));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut s = String::new();
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || chars[i] == '_' || chars[i] == '.')
+                {
+                    s.push(chars[i]);
+                    i += 1;
+                }
+                let token = match s.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    "CONTAINS" => Token::Op(CompareOp::Contains),
+                    "true" => Token::Bool(true),
+                    "false" => Token::Bool(false),
+                    _ => Token::Ident(s),
+                };
+                tokens.push((token, start));
+            }
+            other => {
+                return Err(FilterParseError {
+                    message: format!("unexpected character '{other}'"),
+                    position: start,
+                });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map(|(_, p)| *p)
+            .or_else(|| self.tokens.last().map(|(_, p)| p + 1))
+            .unwrap_or(0)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn expect_end(&self) -> Result<(), FilterParseError> {
+        if self.pos >= self.tokens.len() {
+            Ok(())
+        } else {
+            Err(FilterParseError {
+                message: "unexpected trailing tokens".to_string(),
+                position: self.position(),
+            })
+        }
+    }
+
+    /// or_expr := and_expr (OR and_expr)*
+    fn parse_or(&mut self) -> Result<FilterExpr, FilterParseError> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.advance();
+            let rhs = self.parse_and()?;
+            lhs = FilterExpr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    /// and_expr := unary (AND unary)*
+    fn parse_and(&mut self) -> Result<FilterExpr, FilterParseError> {
+        let mut lhs = self.parse_unary()?;
+        while matches!(self.peek(), Some(Token::And)) {
+            self.advance();
+            let rhs = self.parse_unary()?;
+            lhs = FilterExpr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<FilterExpr, FilterParseError> {
+        match self.peek() {
+            Some(Token::Not) => {
+                self.advance();
+                let inner = self.parse_unary()?;
+                Ok(FilterExpr::Not(Box::new(inner)))
+            }
+            Some(Token::LParen) => {
+                self.advance();
+                let expr = self.parse_or()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err(FilterParseError {
+                        message: "expected closing ')'".to_string(),
+                        position: self.position(),
+                    }),
+                }
+            }
+            _ => self.parse_comparison(),
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<FilterExpr, FilterParseError> {
+        let field_pos = self.position();
+        let field = match self.advance() {
+            Some(Token::Ident(name)) => name,
+            other => {
+                return Err(FilterParseError {
+                    message: format!("expected field name, found {other:?}"),
+                    position: field_pos,
+                });
+            }
+        };
+
+        let op_pos = self.position();
+        let op = match self.advance() {
+            Some(Token::Op(op)) => op,
+            other => {
+                return Err(FilterParseError {
+                    message: format!("expected comparison operator, found {other:?}"),
+                    position: op_pos,
+                });
+            }
+        };
+
+        let value_pos = self.position();
+        let value = match self.advance() {
+            Some(Token::String(s)) => Literal::String(s),
+            Some(Token::Number(n)) => Literal::Number(n),
+            Some(Token::Bool(b)) => Literal::Bool(b),
+            other => {
+                return Err(FilterParseError {
+                    message: format!("expected a literal value, found {other:?}"),
+                    position: value_pos,
+                });
+            }
+        };
+
+        Ok(FilterExpr::Compare { field, op, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn obj(properties: serde_json::Value) -> Object {
+        Object {
+            id: "obj1".to_string(),
+            name: Some("Test".to_string()),
+            space_id: None,
+            object: None,
+            properties,
+            markdown: None,
+        }
+    }
+
+    #[test]
+    fn parses_and_binds_tighter_than_or() {
+        let expr = parse_filter(r#"a = "x" OR b = "y" AND c = "z""#).unwrap();
+        assert_eq!(
+            expr,
+            FilterExpr::Or(
+                Box::new(FilterExpr::Compare {
+                    field: "a".to_string(),
+                    op: CompareOp::Eq,
+                    value: Literal::String("x".to_string()),
+                }),
+                Box::new(FilterExpr::And(
+                    Box::new(FilterExpr::Compare {
+                        field: "b".to_string(),
+                        op: CompareOp::Eq,
+                        value: Literal::String("y".to_string()),
+                    }),
+                    Box::new(FilterExpr::Compare {
+                        field: "c".to_string(),
+                        op: CompareOp::Eq,
+                        value: Literal::String("z".to_string()),
+                    }),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn parses_parens_and_not() {
+        let expr = parse_filter(r#"NOT (status = "done" AND priority > 3)"#).unwrap();
+        assert!(matches!(expr, FilterExpr::Not(_)));
+    }
+
+    #[test]
+    fn rejects_invalid_filter_with_position() {
+        let err = parse_filter("status ==").unwrap_err();
+        assert!(err.message.contains("comparison operator") || err.message.contains("literal"));
+        // The second '=' is the offending token.
+        assert_eq!(err.position, 8);
+    }
+
+    #[test]
+    fn evaluates_dotted_paths() {
+        let expr = parse_filter(r#"details.status = "done""#).unwrap();
+        let object = obj(json!({"details": {"status": "done"}}));
+        assert!(expr.evaluate(&object));
+    }
+
+    #[test]
+    fn missing_field_is_a_non_match() {
+        let expr = parse_filter(r#"missing = "x""#).unwrap();
+        let object = obj(json!({"status": "done"}));
+        assert!(!expr.evaluate(&object));
+    }
+
+    #[test]
+    fn coerces_numeric_strings_for_ordering_comparisons() {
+        let expr = parse_filter("priority > 3").unwrap();
+        let object = obj(json!({"priority": "5"}));
+        assert!(expr.evaluate(&object));
+    }
+
+    #[test]
+    fn coerces_bool_strings_for_equality() {
+        let expr = parse_filter("done = true").unwrap();
+        let object = obj(json!({"done": "true"}));
+        assert!(expr.evaluate(&object));
+    }
+}
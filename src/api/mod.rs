@@ -0,0 +1,5 @@
+//! Anytype API client and types
+
+mod client;
+
+pub use client::*;
@@ -1,7 +1,11 @@
+mod markdown;
+
 use anyhow::{Context, Result};
-use anytype_rs::api::{AnytypeClient, CreateObjectRequest, UpdateObjectRequest};
+use anytype_rs::api::{parse_ndjson, AnytypeClient, CreateObjectRequest, UpdateObjectRequest};
 use clap::{Args, Subcommand};
+use futures::StreamExt;
 use serde_json::json;
+use std::path::PathBuf;
 
 #[derive(Debug, Args)]
 pub struct ObjectsArgs {
@@ -63,6 +67,54 @@ pub enum ObjectsCommand {
         /// Object ID to delete
         object_id: String,
     },
+    /// Search objects in a space using a query and filter expression
+    Search {
+        /// Space ID to search within
+        space_id: String,
+        /// Free-text query
+        #[arg(short, long)]
+        query: Option<String>,
+        /// Filter expression, e.g. 'status = "done" AND priority > 3'
+        #[arg(long)]
+        filter: Option<String>,
+        /// Sort expression passed through to the search endpoint
+        #[arg(long)]
+        sort: Option<String>,
+        /// Limit the number of results
+        #[arg(short, long)]
+        limit: Option<u32>,
+    },
+    /// Apply a batch of create/update/delete operations from an NDJSON file
+    Batch {
+        /// Space ID to apply operations to
+        space_id: String,
+        /// Path to a newline-delimited JSON file of operations
+        file: PathBuf,
+        /// Maximum number of operations to run concurrently
+        #[arg(long, default_value_t = 4)]
+        parallelism: usize,
+        /// Validate and print the planned operations without applying them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export objects in a space to markdown files
+    Export {
+        /// Space ID to export from
+        space_id: String,
+        /// Export a single object instead of the whole space
+        #[arg(long)]
+        object_id: Option<String>,
+        /// Directory to write `.md` files into
+        #[arg(long)]
+        out_dir: PathBuf,
+    },
+    /// Import markdown files back into a space
+    Import {
+        /// Space ID to import into
+        space_id: String,
+        /// Path to a single `.md` file or a directory of them
+        path: PathBuf,
+    },
 }
 
 pub async fn handle_objects_command(args: ObjectsArgs) -> Result<()> {
@@ -95,16 +147,39 @@ pub async fn handle_objects_command(args: ObjectsArgs) -> Result<()> {
             space_id,
             object_id,
         } => delete_object(&client, &space_id, &object_id).await,
+        ObjectsCommand::Search {
+            space_id,
+            query,
+            filter,
+            sort,
+            limit,
+        } => search_objects(&client, &space_id, query, filter, sort, limit).await,
+        ObjectsCommand::Batch {
+            space_id,
+            file,
+            parallelism,
+            dry_run,
+        } => batch_objects(&client, &space_id, &file, parallelism, dry_run).await,
+        ObjectsCommand::Export {
+            space_id,
+            object_id,
+            out_dir,
+        } => export_objects(&client, &space_id, object_id, &out_dir).await,
+        ObjectsCommand::Import { space_id, path } => {
+            import_objects(&client, &space_id, &path).await
+        }
     }
 }
 
 async fn list_objects(client: &AnytypeClient, space_id: &str, limit: Option<u32>) -> Result<()> {
     println!("📦 Fetching objects from space '{space_id}'...");
 
-    let all_objects = client
-        .list_all_objects_with_pagination(space_id, limit.map(|l| l as usize))
-        .await
-        .context("Failed to fetch objects")?;
+    let mut stream = Box::pin(client.list_objects_stream(space_id, limit.map(|l| l as usize)));
+
+    let mut all_objects = Vec::new();
+    while let Some(obj) = stream.next().await {
+        all_objects.push(obj.context("Failed to fetch objects")?);
+    }
 
     if all_objects.is_empty() {
         println!("📭 No objects found in this space.");
@@ -290,6 +365,213 @@ async fn update_object(
     Ok(())
 }
 
+async fn search_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    query: Option<String>,
+    filter: Option<String>,
+    sort: Option<String>,
+    limit: Option<u32>,
+) -> Result<()> {
+    println!("🔎 Searching objects in space '{space_id}'...");
+
+    let results = client
+        .search_objects(
+            space_id,
+            query.as_deref(),
+            filter.as_deref(),
+            sort.as_deref(),
+            limit.map(|l| l as usize),
+        )
+        .await
+        .context("Failed to search objects")?;
+
+    if results.is_empty() {
+        println!("📭 No objects matched the search.");
+        return Ok(());
+    }
+
+    println!("✅ Found {} matching objects:", results.len());
+    println!();
+
+    for obj in results {
+        println!(
+            "  📦 {} ({})",
+            obj.name.as_deref().unwrap_or("Unnamed"),
+            obj.id
+        );
+        println!("     🆔 ID: {}", obj.id);
+
+        if let Some(object_type) = &obj.object {
+            println!("     📋 Type: {object_type}");
+        }
+
+        println!();
+    }
+
+    Ok(())
+}
+
+async fn batch_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    file: &std::path::Path,
+    parallelism: usize,
+    dry_run: bool,
+) -> Result<()> {
+    println!("📂 Reading batch operations from '{}'...", file.display());
+
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read batch file '{}'", file.display()))?;
+    let operations = parse_ndjson(&content).context("Failed to parse batch file")?;
+
+    println!("📋 Parsed {} operations", operations.len());
+
+    if dry_run {
+        println!("🧪 Dry run — no changes will be made:");
+        for (line_number, op) in &operations {
+            println!("  {line_number}: {op:?}");
+        }
+        return Ok(());
+    }
+
+    let report = client.batch_objects(space_id, operations, parallelism).await;
+
+    println!(
+        "✅ Batch complete: {} succeeded, {} failed",
+        report.succeeded, report.failed
+    );
+    for (line_number, error) in &report.errors {
+        println!("  ❌ line {line_number}: {error}");
+    }
+
+    Ok(())
+}
+
+async fn export_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: Option<String>,
+    out_dir: &std::path::Path,
+) -> Result<()> {
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Failed to create output directory '{}'", out_dir.display()))?;
+
+    println!("📦 Exporting objects to '{}'...", out_dir.display());
+
+    let mut exported = 0;
+
+    match object_id {
+        Some(id) => {
+            export_one(client, space_id, &id, out_dir).await?;
+            exported += 1;
+        }
+        None => {
+            // Stream ids one page at a time rather than buffering the whole
+            // space's metadata up front, short-circuiting on errors as they occur.
+            let mut stream = Box::pin(client.list_objects_stream(space_id, None));
+            while let Some(obj) = stream.next().await {
+                let obj = obj.context("Failed to list objects")?;
+                export_one(client, space_id, &obj.id, out_dir).await?;
+                exported += 1;
+            }
+        }
+    }
+
+    println!("✅ Exported {exported} object(s)");
+
+    Ok(())
+}
+
+async fn export_one(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: &str,
+    out_dir: &std::path::Path,
+) -> Result<()> {
+    let obj = client
+        .get_object(space_id, object_id)
+        .await
+        .context("Failed to fetch object")?;
+
+    let body = obj.markdown.clone().unwrap_or_default();
+    let front_matter = markdown::FrontMatter::from_object(&obj);
+    let rendered = markdown::render(&front_matter, &body)?;
+
+    let file_name = markdown::file_name(obj.name.as_deref(), &obj.id);
+    let path = out_dir.join(file_name);
+    std::fs::write(&path, rendered)
+        .with_context(|| format!("Failed to write '{}'", path.display()))?;
+
+    println!("  📝 {}", path.display());
+
+    Ok(())
+}
+
+async fn import_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    path: &std::path::Path,
+) -> Result<()> {
+    let files: Vec<PathBuf> = if path.is_dir() {
+        std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read directory '{}'", path.display()))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("md"))
+            .collect()
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    println!(
+        "📂 Importing {} file(s) into space '{space_id}'...",
+        files.len()
+    );
+
+    let mut created = 0;
+    let mut updated = 0;
+
+    for file in &files {
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read '{}'", file.display()))?;
+        let (front_matter, body) = markdown::parse(&content)
+            .with_context(|| format!("Failed to parse '{}'", file.display()))?;
+
+        let properties = Some(serde_json::Value::Object(front_matter.properties));
+
+        if front_matter.id.is_empty() {
+            let request = CreateObjectRequest {
+                type_key: front_matter.object.unwrap_or_default(),
+                name: front_matter.name,
+                properties,
+                markdown: Some(body),
+            };
+            client
+                .create_object(space_id, request)
+                .await
+                .context("Failed to create object")?;
+            created += 1;
+        } else {
+            let request = UpdateObjectRequest {
+                name: front_matter.name,
+                properties,
+                markdown: Some(body),
+            };
+            client
+                .update_object(space_id, &front_matter.id, request)
+                .await
+                .context("Failed to update object")?;
+            updated += 1;
+        }
+
+        println!("  📝 {}", file.display());
+    }
+
+    println!("✅ Imported {created} new object(s), updated {updated} existing object(s)");
+
+    Ok(())
+}
+
 async fn delete_object(client: &AnytypeClient, space_id: &str, object_id: &str) -> Result<()> {
     println!("⚠️ Deleting (archiving) object '{object_id}' in space '{space_id}'...");
     println!("📝 Note: This will mark the object as archived, not permanently delete it.");
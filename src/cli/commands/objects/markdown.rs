@@ -0,0 +1,120 @@
+//! Markdown front-matter helpers
+//!
+//! Shared by the `export`/`import` commands for round-tripping objects to
+//! `.md` files with a YAML front-matter block.
+
+use anyhow::{Context, Result};
+use anytype_rs::api::Object;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Front matter written at the top of each exported `.md` file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FrontMatter {
+    /// Empty when hand-authored for a new object; `import_objects` routes
+    /// those through `CreateObjectRequest` instead of an update.
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub object: Option<String>,
+    #[serde(flatten)]
+    pub properties: serde_json::Map<String, Value>,
+}
+
+impl FrontMatter {
+    pub fn from_object(obj: &Object) -> Self {
+        Self {
+            id: obj.id.clone(),
+            name: obj.name.clone(),
+            object: obj.object.clone(),
+            properties: obj.properties.as_object().cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// Render an object as a `.md` file: a YAML front-matter block followed by
+/// its markdown body
+pub fn render(front_matter: &FrontMatter, markdown: &str) -> Result<String> {
+    let yaml = serde_yaml::to_string(front_matter).context("Failed to render front matter")?;
+    Ok(format!("---\n{yaml}---\n\n{markdown}"))
+}
+
+/// Parse a previously exported `.md` file back into its front matter and
+/// markdown body
+pub fn parse(content: &str) -> Result<(FrontMatter, String)> {
+    let content = content
+        .strip_prefix("---\n")
+        .context("Missing front-matter block")?;
+    let (yaml, body) = content
+        .split_once("\n---\n")
+        .context("Missing closing front-matter delimiter")?;
+    let front_matter: FrontMatter =
+        serde_yaml::from_str(yaml).context("Failed to parse front matter")?;
+    Ok((front_matter, body.trim_start_matches('\n').to_string()))
+}
+
+/// Build a filesystem-safe file name from an object name, with the object id
+/// appended so exports never collide
+pub fn file_name(name: Option<&str>, id: &str) -> String {
+    let raw: String = name
+        .unwrap_or("untitled")
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect();
+
+    let slug: Vec<&str> = raw.split('-').filter(|s| !s.is_empty()).collect();
+    let slug = if slug.is_empty() {
+        "untitled".to_string()
+    } else {
+        slug.join("-")
+    };
+
+    format!("{slug}-{id}.md")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn round_trips_front_matter_and_body() {
+        let obj = Object {
+            id: "obj1".to_string(),
+            name: Some("My Note".to_string()),
+            space_id: Some("space1".to_string()),
+            object: Some("page".to_string()),
+            properties: json!({"status": "done"}),
+            markdown: None,
+        };
+
+        let front_matter = FrontMatter::from_object(&obj);
+        let rendered = render(&front_matter, "# My Note\n\nBody text.").unwrap();
+
+        let (parsed, body) = parse(&rendered).unwrap();
+        assert_eq!(parsed.id, "obj1");
+        assert_eq!(parsed.name.as_deref(), Some("My Note"));
+        assert_eq!(parsed.object.as_deref(), Some("page"));
+        assert_eq!(
+            parsed.properties.get("status").and_then(|v| v.as_str()),
+            Some("done")
+        );
+        assert_eq!(body, "# My Note\n\nBody text.");
+    }
+
+    #[test]
+    fn parses_hand_authored_file_with_no_id() {
+        let content = "---\nname: New Note\n---\n\nBody.";
+        let (front_matter, body) = parse(content).unwrap();
+        assert_eq!(front_matter.id, "");
+        assert_eq!(front_matter.name.as_deref(), Some("New Note"));
+        assert_eq!(body, "Body.");
+    }
+
+    #[test]
+    fn builds_unique_slugged_file_names() {
+        assert_eq!(file_name(Some("My Note!"), "abc123"), "my-note-abc123.md");
+        assert_eq!(file_name(None, "abc123"), "untitled-abc123.md");
+    }
+}
@@ -0,0 +1,25 @@
+//! Error types
+//!
+//! The error type shared by every `api` module; the `cli` layer wraps these
+//! in `anyhow` for display.
+
+use thiserror::Error;
+
+/// Result type alias used throughout the `api` module
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can occur when talking to the Anytype API
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{0}")]
+    Api(String),
+}
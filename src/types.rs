@@ -0,0 +1,14 @@
+//! Shared types
+//!
+//! Common types used across `api` requests and responses.
+
+use serde::Deserialize;
+
+/// Pagination metadata returned alongside paginated list responses
+#[derive(Debug, Deserialize)]
+pub struct Pagination {
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub has_more: bool,
+}